@@ -1,159 +1,600 @@
 use chrono::{Datelike, Duration, Local, NaiveDate};
+use prettytable::{row, Table};
 use serde::{Deserialize, Serialize};
-use std::{collections::VecDeque, fs, io};
+use std::{
+    collections::{HashMap, VecDeque},
+    fs, io,
+};
+
+/// Last valid day of `month` in `year` (handles leap Februaries).
+fn last_day_of_month(year: i32, month: u32) -> u32 {
+    let (next_year, next_month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+    NaiveDate::from_ymd_opt(next_year, next_month, 1)
+        .unwrap()
+        .pred_opt()
+        .unwrap()
+        .day()
+}
+
+/// Advances `date` by `months`, keeping the original day-of-month where
+/// possible and clamping to the target month's last day otherwise (e.g. the
+/// 31st becomes the 30th in April).
+fn add_months_clamped(date: NaiveDate, months: i32) -> NaiveDate {
+    let anchor_day = date.day();
+    let total_months = date.month0() as i32 + months;
+    let year = date.year() + total_months.div_euclid(12);
+    let month = total_months.rem_euclid(12) as u32 + 1;
+    let day = anchor_day.min(last_day_of_month(year, month));
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+/// Parses one `type,date,amount,recurrence,note` CSV row into a `Transaction`,
+/// returning `None` for any row that doesn't parse instead of panicking.
+/// `recurrence` is `period:count` (e.g. `monthly:6`) or empty for one-time.
+fn parse_csv_record(record: &csv::StringRecord) -> Option<Transaction> {
+    let kind = record.get(0)?.trim();
+    let date = NaiveDate::parse_from_str(record.get(1)?.trim(), "%Y-%m-%d").ok()?;
+    let magnitude: f64 = record.get(2)?.trim().parse().ok()?;
+    let amount = match kind.to_lowercase().as_str() {
+        "credit" => magnitude.abs(),
+        "debit" => -magnitude.abs(),
+        _ => return None,
+    };
+
+    let recurrence_str = record.get(3).unwrap_or("").trim();
+    let recurrence = if recurrence_str.is_empty() {
+        None
+    } else {
+        let mut parts = recurrence_str.splitn(2, ':');
+        let period = parts.next()?.to_string();
+        let count: usize = parts.next()?.parse().ok()?;
+        Some((period, count))
+    };
+
+    let note = record.get(4).unwrap_or("").trim().to_string();
+
+    Some(Transaction {
+        id: 0,
+        amount,
+        date,
+        recurrence,
+        note,
+        status: TransactionStatus::Posted,
+        category: None,
+        transfer_id: None,
+    })
+}
+
+/// Parses a `YYYY-MM-DD` TOML string into a `NaiveDate`.
+fn deserialize_ymd<'de, D>(deserializer: D) -> Result<NaiveDate, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    NaiveDate::parse_from_str(&s, "%Y-%m-%d").map_err(serde::de::Error::custom)
+}
+
+/// `budget.toml` config: a reporting window and a spending limit per category.
+#[derive(Debug, Deserialize)]
+struct CategoryBudgetConfig {
+    #[serde(deserialize_with = "deserialize_ymd")]
+    start_date: NaiveDate,
+    #[serde(deserialize_with = "deserialize_ymd")]
+    end_date: NaiveDate,
+    categories: HashMap<String, f64>,
+}
+
+fn load_category_budgets(path: &str) -> Option<CategoryBudgetConfig> {
+    let data = fs::read_to_string(path).ok()?;
+    match toml::from_str(&data) {
+        Ok(config) => Some(config),
+        Err(e) => {
+            println!("Failed to parse {}: {}", path, e);
+            None
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+enum TransactionStatus {
+    Posted,
+    Disputed,
+    Reversed,
+}
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 struct Transaction {
+    id: u32,
     amount: f64,
     date: NaiveDate,
     recurrence: Option<(String, usize)>, // (weekly, biweekly, monthly), occurrences
     note: String, // A brief note about the transaction
+    status: TransactionStatus,
+    category: Option<String>,
+    transfer_id: Option<u32>, // shared by both legs of a transfer between accounts
+}
+
+/// Expands `transactions` into a date-sorted queue of occurrences, including
+/// generated recurrences, ready to be walked month by month.
+fn expand_events(transactions: &[Transaction]) -> VecDeque<Transaction> {
+    let mut events: VecDeque<Transaction> = VecDeque::new();
+    for t in transactions {
+        events.push_back(t.clone());
+        if let Some((ref period, count)) = t.recurrence {
+            for i in 1..=count {
+                let date = match period.as_str() {
+                    "weekly" => t.date + Duration::weeks(i as i64),
+                    "biweekly" => t.date + Duration::weeks(2 * i as i64),
+                    "monthly" => add_months_clamped(t.date, i as i32),
+                    _ => break,
+                };
+                events.push_back(Transaction {
+                    id: t.id,
+                    amount: t.amount,
+                    date,
+                    recurrence: None,
+                    note: t.note.clone(),
+                    status: t.status,
+                    category: t.category.clone(),
+                    transfer_id: t.transfer_id,
+                });
+            }
+        }
+    }
+    events.make_contiguous().sort_by_key(|t| t.date);
+    events
+}
+
+/// Walks `events` month by month for a year, applying only `Posted` amounts
+/// to `starting_balance` (disputed funds are held, reversed funds never
+/// apply), and returns the end-of-month balance for each of the next 12
+/// months.
+fn project_month_balances(starting_balance: f64, mut events: VecDeque<Transaction>) -> Vec<(NaiveDate, f64)> {
+    let mut balance = starting_balance;
+    let mut month_balances = vec![];
+    let mut current_date = Local::now().date_naive();
+
+    for _ in 0..12 {
+        let next_month = current_date.with_day(1).unwrap() + Duration::days(32);
+        current_date = next_month.with_day(1).unwrap();
+
+        while let Some(t) = events.front() {
+            if t.date >= current_date {
+                break;
+            }
+            if t.status == TransactionStatus::Posted {
+                balance += t.amount;
+            }
+            events.pop_front();
+        }
+
+        month_balances.push((current_date, balance));
+    }
+
+    month_balances
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-struct BudgetState {
+struct Account {
     balance: f64,
     transactions: Vec<Transaction>,
+    next_id: u32,
 }
 
-impl BudgetState {
+impl Account {
     fn new(balance: f64) -> Self {
         Self {
             balance,
             transactions: Vec::new(),
+            next_id: 0,
         }
     }
 
-    fn add_transaction(&mut self, amount: f64, date: NaiveDate, recurrence: Option<(String, usize)>, note: String) {
-        self.transactions.push(Transaction { amount, date, recurrence, note });
+    fn add_transaction(
+        &mut self,
+        amount: f64,
+        date: NaiveDate,
+        recurrence: Option<(String, usize)>,
+        note: String,
+        category: Option<String>,
+    ) {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.transactions.push(Transaction {
+            id,
+            amount,
+            date,
+            recurrence,
+            note,
+            status: TransactionStatus::Posted,
+            category,
+            transfer_id: None,
+        });
+    }
+
+    /// Records one leg of a transfer (no recurrence, tagged with the shared
+    /// `transfer_id` so both legs can be traced back to the same move).
+    fn add_transfer_leg(&mut self, amount: f64, date: NaiveDate, note: String, transfer_id: u32) {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.transactions.push(Transaction {
+            id,
+            amount,
+            date,
+            recurrence: None,
+            note,
+            status: TransactionStatus::Posted,
+            category: None,
+            transfer_id: Some(transfer_id),
+        });
     }
 
     fn list_transactions(&self) {
         println!("\nTransactions:");
-        println!("{:<5}  {:<9}  {:<10}  {:<13}  {}", "Index", "Amount", "Date", "Recurrence", "Note");
-        println!("{}", "-".repeat(60));
-        for (i, t) in self.transactions.iter().enumerate() {
+        let rows: Vec<&Transaction> = self.transactions.iter().collect();
+        Self::render_table(&rows, None);
+    }
+
+    /// Shows only transactions whose date falls within `[from, to]`, marking
+    /// rows whose note contains `highlight`, followed by credit/debit/net
+    /// totals for the filtered view.
+    fn list_range(&self, from: NaiveDate, to: NaiveDate, highlight: Option<String>) {
+        println!("\nTransactions from {} to {}:", from, to);
+        let rows: Vec<&Transaction> = self
+            .transactions
+            .iter()
+            .filter(|t| t.date >= from && t.date <= to)
+            .collect();
+        Self::render_table(&rows, highlight.as_deref());
+    }
+
+    fn render_table(rows: &[&Transaction], highlight: Option<&str>) {
+        let mut table = Table::new();
+        table.set_titles(row!["ID", "Amount", "Date", "Recurrence", "Status", "Note"]);
+
+        let mut credits = 0.0;
+        let mut debits = 0.0;
+        for t in rows {
             let recurrence_str = if let Some((ref period, count)) = t.recurrence {
                 format!("{} ({})", period, count)
             } else {
                 "One-time".to_string()
             };
-            println!("{:<5}  {:<9}  {:<10}  {:<13}  {}", i, t.amount, t.date.to_string(), recurrence_str, t.note);
+            let highlighted = highlight
+                .map(|term| t.note.to_lowercase().contains(&term.to_lowercase()))
+                .unwrap_or(false);
+            let note = if highlighted { format!(">> {} <<", t.note) } else { t.note.clone() };
+
+            table.add_row(row![
+                t.id,
+                format!("{:.2}", t.amount),
+                t.date,
+                recurrence_str,
+                format!("{:?}", t.status),
+                note
+            ]);
+
+            if t.amount >= 0.0 {
+                credits += t.amount;
+            } else {
+                debits += t.amount;
+            }
         }
+
+        table.printstd();
+        println!("Credits: {:.2}  Debits: {:.2}  Net: {:.2}", credits, debits, credits + debits);
     }
 
-    fn delete_transaction(&mut self, index: usize) {
-        if index < self.transactions.len() {
-            self.transactions.remove(index);
-        } else {
-            println!("Invalid transaction ID.");
+    fn delete_transaction(&mut self, id: u32) {
+        match self.transactions.iter().position(|t| t.id == id) {
+            Some(index) if self.transactions[index].status == TransactionStatus::Reversed => {
+                println!("Transaction {} has been charged back and is locked.", id);
+            }
+            Some(index) => {
+                self.transactions.remove(index);
+            }
+            None => println!("Invalid transaction ID."),
         }
     }
 
-    fn edit_transaction(&mut self, index: usize, new_amount: f64, new_date: NaiveDate, new_recurrence: Option<(String, usize)>, new_note: String) {
-        if let Some(t) = self.transactions.get_mut(index) {
-            t.amount = new_amount;
-            t.date = new_date;
-            t.recurrence = new_recurrence;
-            t.note = new_note;
-        } else {
-            println!("Invalid transaction ID.");
+    fn edit_transaction(
+        &mut self,
+        id: u32,
+        new_amount: f64,
+        new_date: NaiveDate,
+        new_recurrence: Option<(String, usize)>,
+        new_note: String,
+        new_category: Option<String>,
+    ) {
+        match self.transactions.iter().position(|t| t.id == id) {
+            Some(index) if self.transactions[index].status == TransactionStatus::Reversed => {
+                println!("Transaction {} has been charged back and is locked.", id);
+            }
+            Some(index) => {
+                let t = &mut self.transactions[index];
+                t.amount = new_amount;
+                t.date = new_date;
+                t.recurrence = new_recurrence;
+                t.note = new_note;
+                t.category = new_category;
+            }
+            None => println!("Invalid transaction ID."),
         }
     }
 
-    fn save_to_file(&self, filename: &str) {
-        let data = serde_json::to_string(self).expect("Failed to serialize");
-        fs::write(filename, data).expect("Failed to write file");
+    fn dispute(&mut self, id: u32) {
+        match self.transactions.iter_mut().find(|t| t.id == id) {
+            Some(t) if t.status == TransactionStatus::Posted => t.status = TransactionStatus::Disputed,
+            Some(t) => println!("Transaction {} cannot be disputed from status {:?}.", id, t.status),
+            None => println!("Transaction {} not found.", id),
+        }
     }
 
-    fn load_from_file(filename: &str) -> Self {
-        if let Ok(data) = fs::read_to_string(filename) {
-            if let Ok(state) = serde_json::from_str(&data) {
-                return state;
+    fn resolve(&mut self, id: u32) {
+        match self.transactions.iter_mut().find(|t| t.id == id) {
+            Some(t) if t.status == TransactionStatus::Disputed => t.status = TransactionStatus::Posted,
+            Some(t) => println!("Transaction {} is not disputed (status {:?}).", id, t.status),
+            None => println!("Transaction {} not found.", id),
+        }
+    }
+
+    fn chargeback(&mut self, id: u32) {
+        match self.transactions.iter_mut().find(|t| t.id == id) {
+            Some(t) if t.status != TransactionStatus::Reversed => t.status = TransactionStatus::Reversed,
+            Some(t) => println!("Transaction {} is already {:?}.", id, t.status),
+            None => println!("Transaction {} not found.", id),
+        }
+    }
+
+    fn import_csv(&mut self, path: &str) {
+        let mut reader = match csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .from_path(path)
+        {
+            Ok(reader) => reader,
+            Err(e) => {
+                println!("Failed to open CSV file: {}", e);
+                return;
+            }
+        };
+
+        let mut imported = 0;
+        let mut skipped = 0;
+        for result in reader.records() {
+            match result.ok().and_then(|record| parse_csv_record(&record)) {
+                Some(mut t) => {
+                    t.id = self.next_id;
+                    self.next_id += 1;
+                    self.transactions.push(t);
+                    imported += 1;
+                }
+                None => skipped += 1,
             }
         }
-        Self::new(0.0)
+
+        println!("Imported {} transaction(s), skipped {} malformed row(s).", imported, skipped);
     }
 
-    fn forecast(&self) {
-        let mut balance = self.balance;
-        let mut events: VecDeque<Transaction> = VecDeque::new();
-        let mut month_balances = vec![];
-        let mut current_date = Local::now().date_naive();
-        // let mut zero_hit = false;
+    fn export_csv(&self, path: &str) {
+        let mut writer = match csv::WriterBuilder::new().has_headers(false).from_path(path) {
+            Ok(writer) => writer,
+            Err(e) => {
+                println!("Failed to create CSV file: {}", e);
+                return;
+            }
+        };
 
-        // Populate transaction queue
         for t in &self.transactions {
-            events.push_back((*t).clone());
-            if let Some((ref period, count)) = t.recurrence {
-                let mut date = t.date;
-                for _ in 0..count {
-                    date = match period.as_str() {
-                        "weekly" => date + Duration::weeks(1),
-                        "biweekly" => date + Duration::weeks(2),
-                        "monthly" => date + Duration::days(30),
-                        _ => break,
-                    };
-                    events.push_back(Transaction { amount: t.amount, date, recurrence: None, note: t.note.clone() });
-                }
+            let kind = if t.amount >= 0.0 { "credit" } else { "debit" };
+            let recurrence_str = t
+                .recurrence
+                .as_ref()
+                .map(|(period, count)| format!("{}:{}", period, count))
+                .unwrap_or_default();
+            if let Err(e) = writer.write_record(&[
+                kind.to_string(),
+                t.date.to_string(),
+                t.amount.abs().to_string(),
+                recurrence_str,
+                t.note.clone(),
+            ]) {
+                println!("Failed to write row: {}", e);
+                return;
             }
         }
 
-        events.make_contiguous().sort_by_key(|t| t.date);
+        match writer.flush() {
+            Ok(()) => println!("Exported {} transaction(s) to {}.", self.transactions.len(), path),
+            Err(e) => println!("Failed to flush CSV file: {}", e),
+        }
+    }
 
-        for _ in 0..12 {
-            let next_month = current_date.with_day(1).unwrap() + Duration::days(32);
-            current_date = next_month.with_day(1).unwrap();
-            
-            while let Some(t) = events.front() {
-                if t.date >= current_date { break; }
-                balance += t.amount;
-                events.pop_front();
+    fn forecast(&self) {
+        let events = expand_events(&self.transactions);
+        for (date, bal) in project_month_balances(self.balance, events) {
+            println!("{}: {:.2}", date.format("%Y-%m"), bal);
+        }
+    }
+
+    /// Sums debits per category within the config's window and flags any
+    /// category that exceeds its configured limit.
+    fn category_report(&self, config: &CategoryBudgetConfig) {
+        let mut spent: HashMap<&str, f64> = HashMap::new();
+        for t in &self.transactions {
+            if t.amount >= 0.0 || t.status != TransactionStatus::Posted {
+                continue;
+            }
+            if t.date < config.start_date || t.date > config.end_date {
+                continue;
             }
-            
-            month_balances.push((current_date, balance));
-            
-            if balance <= 0.0 {
-                // zero_hit = true;
-                // break;
+            if let Some(ref category) = t.category {
+                *spent.entry(category.as_str()).or_insert(0.0) += -t.amount;
             }
         }
 
-        for (date, bal) in month_balances {
+        println!(
+            "\nCategory budget report ({} to {}):",
+            config.start_date, config.end_date
+        );
+        for (category, limit) in &config.categories {
+            let total = spent.get(category.as_str()).copied().unwrap_or(0.0);
+            if total > *limit {
+                println!("{}: {:.2}/{:.2} — over by {:.2}", category, total, limit, total - limit);
+            } else {
+                println!("{}: {:.2}/{:.2}", category, total, limit);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BudgetState {
+    accounts: HashMap<String, Account>,
+    next_transfer_id: u32,
+}
+
+impl BudgetState {
+    fn new() -> Self {
+        Self {
+            accounts: HashMap::new(),
+            next_transfer_id: 0,
+        }
+    }
+
+    fn add_account(&mut self, name: String, opening_balance: f64) {
+        self.accounts.entry(name).or_insert_with(|| Account::new(opening_balance));
+    }
+
+    fn list_accounts(&self) {
+        println!("\nAccounts:");
+        for (name, account) in &self.accounts {
+            println!("{:<15}  balance: {:.2}", name, account.balance);
+        }
+    }
+
+    /// Atomically records a debit on `from` and a matching credit on `to`,
+    /// sharing a transfer id so both legs can be traced back to the same move.
+    fn transfer(&mut self, from: &str, to: &str, amount: f64, date: NaiveDate, note: String) -> Result<(), String> {
+        if !self.accounts.contains_key(from) {
+            return Err(format!("Unknown account '{}'.", from));
+        }
+        if !self.accounts.contains_key(to) {
+            return Err(format!("Unknown account '{}'.", to));
+        }
+        if from == to {
+            return Err("Cannot transfer an account to itself.".to_string());
+        }
+        if amount <= 0.0 {
+            return Err("Transfer amount must be positive.".to_string());
+        }
+
+        let transfer_id = self.next_transfer_id;
+        self.next_transfer_id += 1;
+
+        self.accounts
+            .get_mut(from)
+            .unwrap()
+            .add_transfer_leg(-amount, date, format!("Transfer to {}: {}", to, note), transfer_id);
+        self.accounts
+            .get_mut(to)
+            .unwrap()
+            .add_transfer_leg(amount, date, format!("Transfer from {}: {}", from, note), transfer_id);
+
+        Ok(())
+    }
+
+    /// Forecasts a single account by name.
+    fn forecast_account(&self, name: &str) {
+        match self.accounts.get(name) {
+            Some(account) => account.forecast(),
+            None => println!("Unknown account '{}'.", name),
+        }
+    }
+
+    /// Forecasts the combined balance across all accounts. Transfers net to
+    /// zero here since both legs (a debit and a matching credit) are counted.
+    fn forecast_aggregate(&self) {
+        let starting_balance: f64 = self.accounts.values().map(|a| a.balance).sum();
+        let all_transactions: Vec<Transaction> = self
+            .accounts
+            .values()
+            .flat_map(|a| a.transactions.iter().cloned())
+            .collect();
+        let events = expand_events(&all_transactions);
+        for (date, bal) in project_month_balances(starting_balance, events) {
             println!("{}: {:.2}", date.format("%Y-%m"), bal);
         }
-        
-        // if zero_hit {
-        //     println!("Balance reaches zero/negative within the displayed period.");
-        // }
+    }
+
+    fn save_to_file(&self, filename: &str) {
+        let data = serde_json::to_string(self).expect("Failed to serialize");
+        fs::write(filename, data).expect("Failed to write file");
+    }
+
+    fn load_from_file(filename: &str) -> Self {
+        if let Ok(data) = fs::read_to_string(filename) {
+            if let Ok(state) = serde_json::from_str(&data) {
+                return state;
+            }
+        }
+        Self::new()
     }
 }
 
 fn main() {
     let filename = "budget_state.json";
     let mut budget = BudgetState::load_from_file(filename);
-    
+    let category_budgets = load_category_budgets("budget.toml");
+
     loop {
-        println!("\n1. Add transaction\n2. View transactions\n3. Delete transaction\n4. Edit transaction\n5. View forecast\n6. Exit");
+        println!("\n1. Add account\n2. List accounts\n3. Add transaction\n4. View transactions\n5. Delete transaction\n6. Edit transaction\n7. Dispute transaction\n8. Resolve dispute\n9. Chargeback transaction\n10. Transfer between accounts\n11. View forecast\n12. Import from CSV\n13. Export to CSV\n14. Category budget report\n15. View transactions in date range\n16. Exit");
         let mut choice = String::new();
         io::stdin().read_line(&mut choice).expect("Failed to read input");
-        
+
         match choice.trim() {
             "1" => {
+                println!("Enter account name: ");
+                let mut name = String::new();
+                io::stdin().read_line(&mut name).expect("Failed to read input");
+
+                println!("Enter opening balance: ");
+                let mut balance = String::new();
+                io::stdin().read_line(&mut balance).expect("Failed to read input");
+                let balance: f64 = balance.trim().parse().expect("Invalid amount");
+
+                budget.add_account(name.trim().to_string(), balance);
+                budget.save_to_file(filename);
+            }
+            "2" => budget.list_accounts(),
+            "3" => {
+                println!("Enter account name: ");
+                let mut name = String::new();
+                io::stdin().read_line(&mut name).expect("Failed to read input");
+                let Some(account) = budget.accounts.get_mut(name.trim()) else {
+                    println!("Unknown account '{}'.", name.trim());
+                    continue;
+                };
+
                 println!("Enter amount (positive for credit, negative for debit): ");
                 let mut amount = String::new();
                 io::stdin().read_line(&mut amount).expect("Failed to read input");
                 let amount: f64 = amount.trim().parse().expect("Invalid amount");
-                
+
                 println!("Enter date (YYYY-MM-DD): ");
                 let mut date = String::new();
                 io::stdin().read_line(&mut date).expect("Failed to read input");
                 let date = NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d").expect("Invalid date format");
-                
+
                 println!("Enter a note for this transaction: ");
                 let mut note = String::new();
                 io::stdin().read_line(&mut note).expect("Failed to read input");
-                
+
+                println!("Enter a category for this transaction (leave blank for none): ");
+                let mut category = String::new();
+                io::stdin().read_line(&mut category).expect("Failed to read input");
+                let category = if category.trim().is_empty() { None } else { Some(category.trim().to_string()) };
+
                 println!("Is this a recurring transaction? (yes/no)");
                 let mut recur = String::new();
                 io::stdin().read_line(&mut recur).expect("Failed to read input");
@@ -161,7 +602,7 @@ fn main() {
                     println!("Enter recurrence type (weekly/biweekly/monthly): ");
                     let mut period = String::new();
                     io::stdin().read_line(&mut period).expect("Failed to read input");
-                    
+
                     println!("Enter number of occurrences: ");
                     let mut count = String::new();
                     io::stdin().read_line(&mut count).expect("Failed to read input");
@@ -170,39 +611,68 @@ fn main() {
                 } else {
                     None
                 };
-                
-                budget.add_transaction(amount, date, recurrence, note.trim().to_string());
+
+                account.add_transaction(amount, date, recurrence, note.trim().to_string(), category);
                 budget.save_to_file(filename);
             }
-            "2" => budget.list_transactions(),
-            "3" => {
+            "4" => {
+                println!("Enter account name: ");
+                let mut name = String::new();
+                io::stdin().read_line(&mut name).expect("Failed to read input");
+                match budget.accounts.get(name.trim()) {
+                    Some(account) => account.list_transactions(),
+                    None => println!("Unknown account '{}'.", name.trim()),
+                }
+            }
+            "5" => {
+                println!("Enter account name: ");
+                let mut name = String::new();
+                io::stdin().read_line(&mut name).expect("Failed to read input");
+                let Some(account) = budget.accounts.get_mut(name.trim()) else {
+                    println!("Unknown account '{}'.", name.trim());
+                    continue;
+                };
+
                 println!("Enter transaction ID to delete: ");
-                let mut index = String::new();
-                io::stdin().read_line(&mut index).expect("Failed to read input");
-                let index: usize = index.trim().parse().expect("Invalid number");
-                budget.delete_transaction(index);
+                let mut id = String::new();
+                io::stdin().read_line(&mut id).expect("Failed to read input");
+                let id: u32 = id.trim().parse().expect("Invalid number");
+                account.delete_transaction(id);
                 budget.save_to_file(filename);
             }
-            "4" => {
+            "6" => {
+                println!("Enter account name: ");
+                let mut name = String::new();
+                io::stdin().read_line(&mut name).expect("Failed to read input");
+                let Some(account) = budget.accounts.get_mut(name.trim()) else {
+                    println!("Unknown account '{}'.", name.trim());
+                    continue;
+                };
+
                 println!("Enter transaction ID to edit: ");
-                let mut index = String::new();
-                io::stdin().read_line(&mut index).expect("Failed to read input");
-                let index: usize = index.trim().parse().expect("Invalid number");
-                
+                let mut id = String::new();
+                io::stdin().read_line(&mut id).expect("Failed to read input");
+                let id: u32 = id.trim().parse().expect("Invalid number");
+
                 println!("Enter new amount: ");
                 let mut amount = String::new();
                 io::stdin().read_line(&mut amount).expect("Failed to read input");
                 let amount: f64 = amount.trim().parse().expect("Invalid amount");
-                
+
                 println!("Enter new date (YYYY-MM-DD): ");
                 let mut date = String::new();
                 io::stdin().read_line(&mut date).expect("Failed to read input");
                 let date = NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d").expect("Invalid date format");
-                
+
                 println!("Enter new note: ");
                 let mut note = String::new();
                 io::stdin().read_line(&mut note).expect("Failed to read input");
-                
+
+                println!("Enter new category (leave blank for none): ");
+                let mut category = String::new();
+                io::stdin().read_line(&mut category).expect("Failed to read input");
+                let category = if category.trim().is_empty() { None } else { Some(category.trim().to_string()) };
+
                 println!("Is this a recurring transaction? (yes/no)");
                 let mut recur = String::new();
                 io::stdin().read_line(&mut recur).expect("Failed to read input");
@@ -210,7 +680,7 @@ fn main() {
                     println!("Enter recurrence type (weekly/biweekly/monthly): ");
                     let mut period = String::new();
                     io::stdin().read_line(&mut period).expect("Failed to read input");
-                    
+
                     println!("Enter number of occurrences: ");
                     let mut count = String::new();
                     io::stdin().read_line(&mut count).expect("Failed to read input");
@@ -219,12 +689,167 @@ fn main() {
                 } else {
                     None
                 };
-                
-                budget.edit_transaction(index, amount, date, recurrence, note.trim().to_string());
+
+                account.edit_transaction(id, amount, date, recurrence, note.trim().to_string(), category);
+                budget.save_to_file(filename);
+            }
+            "7" => {
+                println!("Enter account name: ");
+                let mut name = String::new();
+                io::stdin().read_line(&mut name).expect("Failed to read input");
+                let Some(account) = budget.accounts.get_mut(name.trim()) else {
+                    println!("Unknown account '{}'.", name.trim());
+                    continue;
+                };
+
+                println!("Enter transaction ID to dispute: ");
+                let mut id = String::new();
+                io::stdin().read_line(&mut id).expect("Failed to read input");
+                let id: u32 = id.trim().parse().expect("Invalid number");
+                account.dispute(id);
+                budget.save_to_file(filename);
+            }
+            "8" => {
+                println!("Enter account name: ");
+                let mut name = String::new();
+                io::stdin().read_line(&mut name).expect("Failed to read input");
+                let Some(account) = budget.accounts.get_mut(name.trim()) else {
+                    println!("Unknown account '{}'.", name.trim());
+                    continue;
+                };
+
+                println!("Enter transaction ID to resolve: ");
+                let mut id = String::new();
+                io::stdin().read_line(&mut id).expect("Failed to read input");
+                let id: u32 = id.trim().parse().expect("Invalid number");
+                account.resolve(id);
+                budget.save_to_file(filename);
+            }
+            "9" => {
+                println!("Enter account name: ");
+                let mut name = String::new();
+                io::stdin().read_line(&mut name).expect("Failed to read input");
+                let Some(account) = budget.accounts.get_mut(name.trim()) else {
+                    println!("Unknown account '{}'.", name.trim());
+                    continue;
+                };
+
+                println!("Enter transaction ID to chargeback: ");
+                let mut id = String::new();
+                io::stdin().read_line(&mut id).expect("Failed to read input");
+                let id: u32 = id.trim().parse().expect("Invalid number");
+                account.chargeback(id);
+                budget.save_to_file(filename);
+            }
+            "10" => {
+                println!("Enter source account name: ");
+                let mut from = String::new();
+                io::stdin().read_line(&mut from).expect("Failed to read input");
+
+                println!("Enter destination account name: ");
+                let mut to = String::new();
+                io::stdin().read_line(&mut to).expect("Failed to read input");
+
+                println!("Enter amount to transfer: ");
+                let mut amount = String::new();
+                io::stdin().read_line(&mut amount).expect("Failed to read input");
+                let amount: f64 = amount.trim().parse().expect("Invalid amount");
+
+                println!("Enter date (YYYY-MM-DD): ");
+                let mut date = String::new();
+                io::stdin().read_line(&mut date).expect("Failed to read input");
+                let date = NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d").expect("Invalid date format");
+
+                println!("Enter a note for this transfer: ");
+                let mut note = String::new();
+                io::stdin().read_line(&mut note).expect("Failed to read input");
+
+                if let Err(e) = budget.transfer(from.trim(), to.trim(), amount, date, note.trim().to_string()) {
+                    println!("{}", e);
+                } else {
+                    budget.save_to_file(filename);
+                }
+            }
+            "11" => {
+                println!("Enter account name (leave blank to aggregate across all accounts): ");
+                let mut name = String::new();
+                io::stdin().read_line(&mut name).expect("Failed to read input");
+                if name.trim().is_empty() {
+                    budget.forecast_aggregate();
+                } else {
+                    budget.forecast_account(name.trim());
+                }
+            }
+            "12" => {
+                println!("Enter account name: ");
+                let mut name = String::new();
+                io::stdin().read_line(&mut name).expect("Failed to read input");
+                let Some(account) = budget.accounts.get_mut(name.trim()) else {
+                    println!("Unknown account '{}'.", name.trim());
+                    continue;
+                };
+
+                println!("Enter path to CSV file to import: ");
+                let mut path = String::new();
+                io::stdin().read_line(&mut path).expect("Failed to read input");
+                account.import_csv(path.trim());
                 budget.save_to_file(filename);
             }
-            "5" => budget.forecast(),
-            "6" => break,
+            "13" => {
+                println!("Enter account name: ");
+                let mut name = String::new();
+                io::stdin().read_line(&mut name).expect("Failed to read input");
+                let Some(account) = budget.accounts.get(name.trim()) else {
+                    println!("Unknown account '{}'.", name.trim());
+                    continue;
+                };
+
+                println!("Enter path to CSV file to export to: ");
+                let mut path = String::new();
+                io::stdin().read_line(&mut path).expect("Failed to read input");
+                account.export_csv(path.trim());
+            }
+            "14" => {
+                println!("Enter account name: ");
+                let mut name = String::new();
+                io::stdin().read_line(&mut name).expect("Failed to read input");
+                let Some(account) = budget.accounts.get(name.trim()) else {
+                    println!("Unknown account '{}'.", name.trim());
+                    continue;
+                };
+
+                match &category_budgets {
+                    Some(config) => account.category_report(config),
+                    None => println!("No budget.toml found or it failed to parse."),
+                }
+            }
+            "15" => {
+                println!("Enter account name: ");
+                let mut name = String::new();
+                io::stdin().read_line(&mut name).expect("Failed to read input");
+                let Some(account) = budget.accounts.get(name.trim()) else {
+                    println!("Unknown account '{}'.", name.trim());
+                    continue;
+                };
+
+                println!("Enter start date (YYYY-MM-DD): ");
+                let mut from = String::new();
+                io::stdin().read_line(&mut from).expect("Failed to read input");
+                let from = NaiveDate::parse_from_str(from.trim(), "%Y-%m-%d").expect("Invalid date format");
+
+                println!("Enter end date (YYYY-MM-DD): ");
+                let mut to = String::new();
+                io::stdin().read_line(&mut to).expect("Failed to read input");
+                let to = NaiveDate::parse_from_str(to.trim(), "%Y-%m-%d").expect("Invalid date format");
+
+                println!("Enter a keyword to highlight in notes (leave blank for none): ");
+                let mut highlight = String::new();
+                io::stdin().read_line(&mut highlight).expect("Failed to read input");
+                let highlight = if highlight.trim().is_empty() { None } else { Some(highlight.trim().to_string()) };
+
+                account.list_range(from, to, highlight);
+            }
+            "16" => break,
             _ => println!("Invalid option, try again."),
         }
     }